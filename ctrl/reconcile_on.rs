@@ -0,0 +1,60 @@
+use std::{sync::Arc, time::Duration};
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::apps::v1::Deployment;
+use kube::{
+    Api, Client, ResourceExt,
+    runtime::reflector::{self, ObjectRef},
+    runtime::controller::{Action, Controller},
+    runtime::{watcher, WatchStreamExt},
+};
+use tokio::time::interval;
+use tokio_stream::wrappers::IntervalStream;
+
+const TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {}
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[tokio::main]
+async fn main() -> Result<(), kube::Error> {
+    let client = Client::try_default().await?;
+    let deploys = Api::<Deployment>::all(client);
+
+    // reflect Deployments into a store so the reconciler can read cached objects
+    let (store, writer) = reflector::store::<Deployment>();
+    let deploy_reflector = watcher(deploys.clone(), Default::default())
+        .default_backoff()
+        .reflect(writer)
+        .applied_objects()
+        .for_each(|_| futures::future::ready(()));
+
+    // an arbitrary, non-Kubernetes trigger: a timer re-emitting every managed object
+    let ticker = tick_all_managed(store.clone());
+
+    let controller = Controller::new(deploys, Default::default())
+        .reconcile_on(ticker)
+        .run(reconcile, error_policy, Arc::new(store))
+        .for_each(|_| futures::future::ready(()));
+
+    tokio::join!(deploy_reflector, controller);
+
+    Ok(())
+}
+
+/// Re-queues every `Deployment` currently in the store on each tick, mirroring how you'd
+/// wire in a message bus or HTTP poll that doesn't originate from the Kubernetes API.
+fn tick_all_managed(store: reflector::Store<Deployment>) -> impl Stream<Item = ObjectRef<Deployment>> {
+    IntervalStream::new(interval(TICK_INTERVAL)).flat_map(move |_| {
+        futures::stream::iter(store.state().into_iter().map(|obj| ObjectRef::from_obj(&obj)))
+    })
+}
+
+async fn reconcile(obj: Arc<Deployment>, _ctx: Arc<reflector::Store<Deployment>>) -> Result<Action> {
+    println!("reconcile request: {}", obj.name_any());
+    Ok(Action::requeue(Duration::from_secs(3600)))
+}
+
+fn error_policy(_obj: Arc<Deployment>, _error: &Error, _ctx: Arc<reflector::Store<Deployment>>) -> Action {
+    Action::requeue(Duration::from_secs(5))
+}
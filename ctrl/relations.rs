@@ -14,15 +14,22 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 #[tokio::main]
 async fn main() -> Result<(), kube::Error> {
     let client = Client::try_default().await?;
-    let deploys = Api::<Deployment>::all(client.clone());
-    let hpas = Api::<HorizontalPodAutoscaler>::all(client);
+    // HorizontalPodAutoscaler is namespaced, so scope both Apis to one namespace; swap these
+    // for `Api::all` if you really do need a cluster-wide watch (the mapper's `.within(ns)`
+    // below keeps cross-references correct either way).
+    let ns = client.default_namespace().to_string();
+    let deploys = Api::<Deployment>::namespaced(client.clone(), &ns);
+    let hpas = Api::<HorizontalPodAutoscaler>::namespaced(client, &ns);
 
     // map hpa changes to deployment events through scaleTargetRef
+    // NB: scaleTargetRef never crosses namespaces, so the ref must be scoped with `.within(ns)`,
+    // otherwise an HPA in namespace `a` could trigger a same-named Deployment in namespace `b`.
     let mapper = |obj: HorizontalPodAutoscaler| {
+        let ns = obj.namespace();
         obj.spec.map(|hspec| {
             let crossref = hspec.scale_target_ref;
             if crossref.kind == "Deployment" {
-                Some(ObjectRef::new_with(&crossref.name, ()))
+                Some(ObjectRef::new_with(&crossref.name, ()).within(&ns?))
             } else {
                 None
             }
@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use futures::StreamExt;
+use k8s_openapi::api::{autoscaling::v2::HorizontalPodAutoscaler, apps::v1::Deployment};
+use kube::{
+    Api, Client, ResourceExt,
+    runtime::reflector::ObjectRef,
+    runtime::controller::{self, Action, Controller},
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {}
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+// tracks consecutive reconcile failures per object, so error_policy can back off exponentially
+// instead of requeuing transient and terminal failures at the same fixed delay
+type FailureCounts = Mutex<HashMap<ObjectRef<Deployment>, u32>>;
+
+#[tokio::main]
+async fn main() -> Result<(), kube::Error> {
+    let client = Client::try_default().await?;
+    let deploys = Api::<Deployment>::all(client.clone());
+    let hpas = Api::<HorizontalPodAutoscaler>::all(client);
+
+    let mapper = |obj: HorizontalPodAutoscaler| {
+        let ns = obj.namespace();
+        obj.spec.map(|hspec| {
+            let crossref = hspec.scale_target_ref;
+            if crossref.kind == "Deployment" {
+                Some(ObjectRef::new_with(&crossref.name, ()).within(&ns?))
+            } else {
+                None
+            }
+        }).flatten()
+    };
+
+    let ctx = Arc::new(FailureCounts::default());
+
+    Controller::new(deploys.clone(), Default::default())
+        .watches(hpas, Default::default(), mapper)
+        .run(reconcile, error_policy, ctx)
+        .for_each(|res| async {
+            match res {
+                Ok((obj_ref, _action)) => tracing::debug!(%obj_ref, "reconciled"),
+                Err(controller::Error::ReconcilerFailed(err, obj_ref)) => {
+                    tracing::warn!(%obj_ref, %err, "reconcile failed")
+                }
+                Err(err) => tracing::warn!(%err, "controller internal error"),
+            }
+        })
+        .await;
+
+    Ok(())
+}
+
+async fn reconcile(obj: Arc<Deployment>, ctx: Arc<FailureCounts>) -> Result<Action> {
+    println!("reconcile request: {}", obj.name_any());
+    ctx.lock().unwrap().remove(&ObjectRef::from_obj(&obj));
+    Ok(Action::requeue(Duration::from_secs(3600)))
+}
+
+// backs off exponentially per-object (capped at 5 minutes) rather than a fixed delay, so a
+// transient error on one Deployment doesn't hammer the apiserver while another is still healthy
+fn error_policy(obj: Arc<Deployment>, _error: &Error, ctx: Arc<FailureCounts>) -> Action {
+    let obj_ref = ObjectRef::from_obj(&obj);
+    let mut counts = ctx.lock().unwrap();
+    let failures = counts.entry(obj_ref).or_insert(0);
+    *failures += 1;
+
+    let delay = Duration::from_secs(5 * 2u64.pow((*failures - 1).min(6))).min(Duration::from_secs(300));
+    Action::requeue(delay)
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use futures::StreamExt;
+use k8s_openapi::api::{autoscaling::v2::HorizontalPodAutoscaler, apps::v1::Deployment};
+use kube::{
+    Api, Client, ResourceExt,
+    runtime::reflector::{self, ObjectRef},
+    runtime::controller::{Action, Controller},
+    runtime::{watcher, WatchStreamExt},
+};
+use std::time::Duration;
+
+// buffer size of the subscribable store that shares one HorizontalPodAutoscaler watch
+// with every controller below
+const SUBSCRIBE_BUFFER_SIZE: usize = 256;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {}
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[tokio::main]
+async fn main() -> Result<(), kube::Error> {
+    let client = Client::try_default().await?;
+    let deploys = Api::<Deployment>::all(client.clone());
+    let hpas = Api::<HorizontalPodAutoscaler>::all(client);
+
+    // a subscribable store: the buffer bounds how far a slow subscriber may lag the writer
+    let (hpa_store, hpa_writer) = reflector::store_shared(SUBSCRIBE_BUFFER_SIZE);
+    let hpa_subscriber = hpa_writer
+        .subscribe()
+        .expect("subscribers can only be created from the initial writer");
+
+    // drive the single underlying watch; every subscriber rides along for free
+    let hpa_reflector = watcher(hpas, Default::default())
+        .default_backoff()
+        .reflect(hpa_writer)
+        .applied_objects()
+        .for_each(|_| futures::future::ready(()));
+
+    // map hpa changes to deployment events through scaleTargetRef
+    // scaleTargetRef never crosses namespaces, so scope the ref with `.within(ns)`
+    let mapper = |obj: Arc<HorizontalPodAutoscaler>| {
+        let ns = obj.namespace()?;
+        obj.spec.clone().and_then(|hspec| {
+            let crossref = hspec.scale_target_ref;
+            (crossref.kind == "Deployment").then(|| ObjectRef::new_with(&crossref.name, ()).within(&ns))
+        })
+    };
+
+    let by_scale_target_ref = Controller::new(deploys.clone(), Default::default())
+        .watches_shared_stream(hpa_subscriber.clone(), mapper)
+        .run(reconcile, error_policy, Arc::new(hpa_store.clone()))
+        .for_each(|_| futures::future::ready(()));
+
+    let by_owner_ref = Controller::new(deploys, Default::default())
+        .owns_shared_stream(hpa_subscriber)
+        .run(reconcile, error_policy, Arc::new(hpa_store))
+        .for_each(|_| futures::future::ready(()));
+
+    tokio::join!(hpa_reflector, by_scale_target_ref, by_owner_ref);
+
+    Ok(())
+}
+
+async fn reconcile(obj: Arc<Deployment>, ctx: Arc<reflector::Store<HorizontalPodAutoscaler>>) -> Result<Action> {
+    let hpas_for_ns = ctx.state().into_iter().filter(|hpa| hpa.namespace() == obj.namespace());
+    println!(
+        "reconcile request: {} (cached hpas in ns: {})",
+        obj.name_any(),
+        hpas_for_ns.count()
+    );
+    Ok(Action::requeue(Duration::from_secs(3600)))
+}
+
+fn error_policy(_obj: Arc<Deployment>, _error: &Error, _ctx: Arc<reflector::Store<HorizontalPodAutoscaler>>) -> Action {
+    Action::requeue(Duration::from_secs(5))
+}